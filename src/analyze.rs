@@ -0,0 +1,235 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::generators::passphrase::DEFAULT_WORDLIST;
+
+const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~ ";
+
+// Common weak passwords that show up constantly in real-world breached password lists; some of
+// them ("battery") also appear in the passphrase wordlist. Kept separate from, and checked alongside,
+// the passphrase wordlist so that classic weak passwords (`Password1!`, `qwerty123`, …) are
+// discounted as well as plain dictionary words.
+const WEAK_PASSWORDS: [&str; 20] = [
+    "password", "correct", "horse", "battery", "staple", "admin", "welcome",
+    "login", "master", "dragon", "monkey", "shadow", "letmein", "qwerty",
+    "iloveyou", "sunshine", "princess", "football", "superman", "trustno"
+];
+
+/// The words that back the dictionary-hit check: the passphrase wordlist, supplemented with a
+/// dedicated list of common weak passwords.
+fn dictionary_words() -> impl Iterator<Item = &'static str> {
+    DEFAULT_WORDLIST.lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .chain(WEAK_PASSWORDS.iter().copied())
+}
+
+/// A qualitative bucket for a computed entropy estimate.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rating {
+    VeryWeak,
+    Weak,
+    Reasonable,
+    Strong
+}
+
+impl Display for Rating {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Rating::VeryWeak => "very weak",
+            Rating::Weak => "weak",
+            Rating::Reasonable => "reasonable",
+            Rating::Strong => "strong"
+        };
+
+        write!(formatter, "{label}")
+    }
+}
+
+impl Rating {
+    fn from_bits(bits: f64) -> Rating {
+        if bits < 28.0 { Rating::VeryWeak }
+        else if bits < 36.0 { Rating::Weak }
+        else if bits < 60.0 { Rating::Reasonable }
+        else { Rating::Strong }
+    }
+}
+
+/// An entropy estimate for a single candidate (one line of input, or the whole buffer).
+pub struct EntropyReport {
+    /// The alphabet size `N` inferred from the character classes present in the candidate.
+    pub alphabet_size: u32,
+    /// `length * log2(N)`, ignoring any structure in the candidate.
+    pub brute_force_bits: f64,
+    /// The brute-force estimate discounted for repeated runs, sequential runs, and dictionary hits.
+    pub pattern_aware_bits: f64,
+    /// A qualitative rating derived from `pattern_aware_bits`.
+    pub rating: Rating
+}
+
+/// Infer the alphabet size `N` of a candidate by detecting which character classes it draws from.
+fn alphabet_size(candidate: &str) -> u32 {
+    let mut size = 0;
+
+    if candidate.chars().any(|character| character.is_ascii_lowercase()) { size += 26; }
+    if candidate.chars().any(|character| character.is_ascii_uppercase()) { size += 26; }
+    if candidate.chars().any(|character| character.is_ascii_digit()) { size += 10; }
+    if candidate.chars().any(|character| SYMBOLS.contains(character)) { size += SYMBOLS.chars().count() as u32; }
+    if candidate.chars().any(|character| !character.is_ascii()) { size += 1000; }
+
+    size.max(1)
+}
+
+/// Discount, in characters, for maximal runs of three or more identical characters.
+fn repeated_run_discount(characters: &[char]) -> usize {
+    let mut discount = 0;
+    let mut index = 0;
+
+    while index < characters.len() {
+        let mut end = index;
+
+        while end + 1 < characters.len() && characters[end + 1] == characters[index] { end += 1; }
+
+        let run_length = end - index + 1;
+
+        if run_length >= 3 { discount += run_length - 1; }
+
+        index = end + 1;
+    }
+
+    discount
+}
+
+/// Discount, in characters, for maximal runs of three or more ascending or descending
+/// sequential characters, e.g. `abc` or `321`.
+fn sequential_run_discount(characters: &[char]) -> usize {
+    let mut discount = 0;
+    let mut index = 0;
+
+    while index < characters.len() {
+        let mut end = index;
+        let mut ascending = None;
+
+        while end + 1 < characters.len() {
+            let delta = characters[end + 1] as i32 - characters[end] as i32;
+
+            match (delta, ascending) {
+                (1, None) => { ascending = Some(true); }
+                (-1, None) => { ascending = Some(false); }
+                (1, Some(true)) | (-1, Some(false)) => {}
+                _ => break
+            }
+
+            end += 1;
+        }
+
+        let run_length = end - index + 1;
+
+        if run_length >= 3 { discount += run_length - 1; }
+
+        index = if run_length >= 3 { end + 1 } else { index + 1 };
+    }
+
+    discount
+}
+
+/// Discount, in bits, for substrings that match an entry in the passphrase wordlist or the list
+/// of common weak passwords.
+fn dictionary_discount(candidate: &str, alphabet_size: u32) -> f64 {
+    let lower = candidate.to_lowercase();
+    let alphabet_bits = (alphabet_size as f64).log2();
+    let words: Vec<&str> = dictionary_words().collect();
+    let dictionary_bits = (words.len() as f64).log2();
+
+    words.iter()
+        .filter(|word| lower.contains(*word))
+        .map(|word| (word.len() as f64 * alphabet_bits - dictionary_bits).max(0.0))
+        .sum()
+}
+
+/// Estimate the entropy of a single candidate secret.
+pub fn estimate_entropy(candidate: &str) -> EntropyReport {
+    let characters: Vec<char> = candidate.chars().collect();
+    let alphabet_size = alphabet_size(candidate);
+    let brute_force_bits = characters.len() as f64 * (alphabet_size as f64).log2();
+
+    let structure_discount = repeated_run_discount(&characters) + sequential_run_discount(&characters);
+    let structure_bits = structure_discount as f64 * (alphabet_size as f64).log2();
+    let pattern_aware_bits = (brute_force_bits - structure_bits - dictionary_discount(candidate, alphabet_size)).max(0.0);
+
+    EntropyReport {
+        alphabet_size,
+        brute_force_bits,
+        pattern_aware_bits,
+        rating: Rating::from_bits(pattern_aware_bits)
+    }
+}
+
+/// Estimate the entropy of a buffer of input, treating it as one candidate per line when the
+/// buffer contains newlines, or as a single candidate otherwise.
+pub fn estimate_entropy_from_bytes(input: &[u8]) -> Vec<EntropyReport> {
+    let text = String::from_utf8_lossy(input);
+
+    if text.contains('\n') {
+        text.lines()
+            .filter(|line| !line.is_empty())
+            .map(estimate_entropy)
+            .collect()
+    } else {
+        vec![estimate_entropy(text.trim_end_matches(['\r', '\n']))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{estimate_entropy, estimate_entropy_from_bytes, Rating};
+
+    #[test]
+    fn discounts_dictionary_hits_against_the_passphrase_wordlist() {
+        let report = estimate_entropy("Valley2024!");
+
+        assert!(report.pattern_aware_bits < report.brute_force_bits);
+    }
+
+    #[test]
+    fn rates_simple_structured_password_as_weak() {
+        let report = estimate_entropy("Password1!");
+
+        assert!(report.pattern_aware_bits < report.brute_force_bits);
+        assert!(matches!(report.rating, Rating::VeryWeak | Rating::Weak));
+    }
+
+    #[test]
+    fn rates_long_random_string_as_strong() {
+        let report = estimate_entropy("qX7$mK2#pL9@zR4&");
+
+        assert_eq!(report.rating, Rating::Strong);
+    }
+
+    #[test]
+    fn discounts_repeated_runs() {
+        let report = estimate_entropy("aaaaaaaaaa");
+
+        assert!(report.pattern_aware_bits < report.brute_force_bits);
+    }
+
+    #[test]
+    fn discounts_sequential_runs() {
+        let report = estimate_entropy("abcdefgh12345678");
+
+        assert!(report.pattern_aware_bits < report.brute_force_bits);
+    }
+
+    #[test]
+    fn splits_multiline_input_into_one_candidate_per_line() {
+        let reports = estimate_entropy_from_bytes(b"foo\nbar\n");
+
+        assert_eq!(reports.len(), 2);
+    }
+
+    #[test]
+    fn treats_single_line_input_as_one_candidate() {
+        let reports = estimate_entropy_from_bytes(b"foobar");
+
+        assert_eq!(reports.len(), 1);
+    }
+}
@@ -83,6 +83,21 @@ pub enum GenerateCommands {
         #[arg(short = 'S', long = "no-symbols", help = "Don't include any symbols", action = ArgAction::SetFalse)]
         symbols: bool,
 
+        #[arg(long = "require-digits", help = "Guarantee that at least one digit is present")]
+        require_digits: bool,
+
+        #[arg(long = "require-symbols", help = "Guarantee that at least one symbol is present")]
+        require_symbols: bool,
+
+        #[arg(long = "require-uppercase", help = "Guarantee that at least one uppercase letter is present")]
+        require_uppercase: bool,
+
+        #[arg(long = "require-lowercase", help = "Guarantee that at least one lowercase letter is present")]
+        require_lowercase: bool,
+
+        #[arg(short = 'A', long = "no-ambiguous", help = "Exclude visually confusable characters, e.g. 'l', 'I', '1', 'O', '0', '|'")]
+        no_ambiguous: bool,
+
         /// The number of characters to generate
         length: usize,
 
@@ -133,6 +148,57 @@ pub enum GenerateCommands {
         /// How many numbers to generate
         count: Option<usize>
     },
+    /// Generate a random string matching a regular expression
+    Regex {
+        /// The regular expression to match
+        pattern: String,
+
+        #[arg(short = 'r', long = "max-repeat", help = "The maximum number of times an unbounded repetition (*, +, {n,}) may repeat", default_value = "32")]
+        max_repeat: u32,
+
+        /// How many strings to generate
+        count: Option<usize>
+    },
+    /// Generate a memorable password composed of real words decorated with digits and symbols
+    Readable {
+        #[arg(short = 'p', long = "path", help = "The wordlist file to read into memory")]
+        path: Option<PathBuf>,
+
+        #[arg(short = 'D', long = "delimiter", help = "The string used to separate words from each other in the wordlist", default_value = "\n")]
+        delimiter: String,
+
+        #[arg(short = 'm', long = "min-length", help = "The minimum total character length", default_value = "12")]
+        minimum_length: usize,
+
+        #[arg(short = 'M', long = "max-length", help = "The maximum total character length", default_value = "20")]
+        maximum_length: usize,
+
+        #[arg(short = 'g', long = "digits", help = "How many digits to insert", default_value = "1")]
+        digits: usize,
+
+        #[arg(short = 'y', long = "symbols", help = "How many symbols to insert", default_value = "1")]
+        symbols: usize,
+
+        #[arg(short = 'b', long = "between-words", help = "Only insert digits and symbols between words, never inside them")]
+        between_words: bool,
+
+        #[arg(short = 'c', long = "no-randomize-case", help = "Do not randomize the case of each word", action = ArgAction::SetFalse)]
+        randomize_case: bool,
+
+        /// How many passwords to generate
+        count: Option<usize>
+    },
+    /// Generate a string from a hashcat-style mask template, e.g. `?u?l?l?l?l?d?d?d?d`
+    Mask {
+        /// The mask template to expand
+        mask: String,
+
+        #[arg(short = 'c', long = "charset", help = "A custom charset available to the mask as ?1..?9, in the order given")]
+        charsets: Vec<String>,
+
+        /// How many masked strings to generate
+        count: Option<usize>
+    },
     /// Generate a random word using a Markov model
     Markov {
         #[arg(short = 'C', long = "no-capitalize", help = "Do not capitalize words", action = ArgAction::SetFalse)]
@@ -172,6 +238,17 @@ pub enum UsernameCommands {
 
         /// How many syllabic usernames to generate
         count: Option<usize>
+    },
+    /// Generate a username from a language-classified, weighted syllable file
+    Themed {
+        #[arg(short = 'p', long = "path", help = "A syllable file to read into memory, or leave empty to use an embedded default")]
+        path: Option<PathBuf>,
+
+        #[command(flatten)]
+        center_range: CenterRange,
+
+        /// How many themed usernames to generate
+        count: Option<usize>
     }
 }
 
@@ -185,6 +262,16 @@ pub struct LengthRange {
     pub maximum: usize
 }
 
+#[derive(Args)]
+#[group(multiple = true)]
+pub struct CenterRange {
+    #[arg(short = 'm', long = "min", help = "The minimum number of center syllables", default_value = "1")]
+    pub minimum: usize,
+
+    #[arg(short = 'M', long = "max", help = "The maximum number of center syllables", default_value = "3")]
+    pub maximum: usize
+}
+
 #[derive(Args)]
 #[group(multiple = true)]
 pub struct ModelParameters {
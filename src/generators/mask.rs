@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use rand::{thread_rng, Rng};
+use rand::rngs::ThreadRng;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+#[derive(Debug)]
+pub enum MaskError {
+    TrailingPlaceholder,
+    UnknownCustomCharset(usize),
+    UnknownPlaceholder(char),
+    EmptyCharset(char)
+}
+
+impl Display for MaskError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MaskError::TrailingPlaceholder => write!(formatter, "mask ends with a lone '?'"),
+            MaskError::UnknownCustomCharset(id) => write!(formatter, "mask references custom charset ?{id}, but no corresponding --charset was given"),
+            MaskError::UnknownPlaceholder(token) => write!(formatter, "'?{token}' is not a recognized placeholder"),
+            MaskError::EmptyCharset(token) => write!(formatter, "charset for placeholder '?{token}' is empty")
+        }
+    }
+}
+
+impl Error for MaskError {}
+
+/// Resolve a single mask placeholder token to the pool of characters it may draw from.
+fn resolve_charset<'a>(token: char, charsets: &'a [String]) -> Result<Vec<char>, MaskError> {
+    let pool: Vec<char> = match token {
+        'd' => DIGITS.chars().collect(),
+        'l' => LOWERCASE.chars().collect(),
+        'u' => UPPERCASE.chars().collect(),
+        's' => SYMBOLS.chars().collect(),
+        'a' => LOWERCASE.chars()
+            .chain(UPPERCASE.chars())
+            .chain(DIGITS.chars())
+            .chain(SYMBOLS.chars())
+            .collect(),
+        '1'..='9' => {
+            let id = token.to_digit(10).unwrap() as usize;
+
+            charsets.get(id - 1)
+                .ok_or(MaskError::UnknownCustomCharset(id))?
+                .chars()
+                .collect()
+        }
+        _ => return Err(MaskError::UnknownPlaceholder(token))
+    };
+
+    if pool.is_empty() { return Err(MaskError::EmptyCharset(token)); }
+
+    Ok(pool)
+}
+
+fn choose(pool: &[char], rng: &mut ThreadRng) -> char {
+    pool[rng.gen_range(0..pool.len())]
+}
+
+/// Generate a single string by expanding a hashcat-style mask template.
+///
+/// The mask is scanned left to right. `?d`, `?l`, `?u`, `?s` and `?a` draw from the digit,
+/// lowercase, uppercase, symbol, and combined-all charsets respectively; `?1`..`?9` draw from
+/// the Nth custom charset supplied via `charsets`, in the order given; `??` emits a literal `?`;
+/// and any other character is emitted as-is.
+fn expand_mask(mask: &str, charsets: &[String]) -> Result<Vec<char>, MaskError> {
+    let mut output = Vec::new();
+    let mut characters = mask.chars().peekable();
+    let rng = &mut thread_rng();
+
+    while let Some(character) = characters.next() {
+        if character != '?' {
+            output.push(character);
+            continue;
+        }
+
+        let token = characters.next().ok_or(MaskError::TrailingPlaceholder)?;
+
+        if token == '?' {
+            output.push('?');
+            continue;
+        }
+
+        let pool = resolve_charset(token, charsets)?;
+
+        output.push(choose(&pool, rng));
+    }
+
+    Ok(output)
+}
+
+/// Generate a random string matching a hashcat-style mask template.
+///
+/// See [`expand_mask`] for the supported placeholder syntax.
+pub fn generate_mask(mask: &str, charsets: &[String]) -> Result<Vec<u8>, MaskError> {
+    let characters = expand_mask(mask, charsets)?;
+
+    Ok(characters.iter().collect::<String>().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::from_utf8;
+
+    use super::{generate_mask, MaskError};
+
+    #[test]
+    fn generates_mask_of_expected_length() {
+        let bytes = generate_mask("?u?l?l?l?l?d?d?d?d", &[]).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert_eq!(string.chars().count(), 9);
+    }
+
+    #[test]
+    fn emits_literal_characters() {
+        let bytes = generate_mask("hello-?d", &[]).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert!(string.starts_with("hello-"));
+    }
+
+    #[test]
+    fn emits_literal_question_mark() {
+        let bytes = generate_mask("??", &[]).unwrap();
+
+        assert_eq!(bytes, b"?");
+    }
+
+    #[test]
+    fn draws_from_custom_charset() {
+        let bytes = generate_mask("?1", &["x".to_string()]).unwrap();
+
+        assert_eq!(bytes, b"x");
+    }
+
+    #[test]
+    fn rejects_trailing_lone_placeholder() {
+        let result = generate_mask("abc?", &[]);
+
+        assert!(matches!(result, Err(MaskError::TrailingPlaceholder)));
+    }
+
+    #[test]
+    fn rejects_unknown_placeholder() {
+        let result = generate_mask("?x", &[]);
+
+        assert!(matches!(result, Err(MaskError::UnknownPlaceholder('x'))));
+    }
+
+    #[test]
+    fn rejects_missing_custom_charset() {
+        let result = generate_mask("?1", &[]);
+
+        assert!(matches!(result, Err(MaskError::UnknownCustomCharset(1))));
+    }
+
+    #[test]
+    fn rejects_empty_custom_charset() {
+        let result = generate_mask("?1", &[String::new()]);
+
+        assert!(matches!(result, Err(MaskError::EmptyCharset('1'))));
+    }
+}
@@ -0,0 +1,86 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rand::{thread_rng, Rng};
+use rand::seq::SliceRandom;
+
+// A small embedded wordlist used when the caller does not supply one of their own.
+pub(crate) const DEFAULT_WORDLIST: &str = "\
+apple
+battery
+canyon
+desert
+ember
+falcon
+glacier
+harbor
+island
+jungle
+kettle
+lantern
+meadow
+nebula
+orchid
+pepper
+quartz
+raven
+summit
+thicket
+umbrella
+valley
+willow
+yonder
+zephyr";
+
+/// Load a wordlist from a file on disk, or fall back to a small embedded default, and split it
+/// on `delimiter` into individual words.
+pub fn load_words(path: Option<&Path>, delimiter: &str) -> io::Result<Vec<String>> {
+    let content = match path {
+        Some(path) => fs::read_to_string(path)?,
+        None => DEFAULT_WORDLIST.to_string()
+    };
+
+    Ok(content.split(delimiter)
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Generate a passphrase by joining `length` randomly chosen words with `separator`.
+pub fn generate_passphrase(length: usize, separator: &str, words: &[String]) -> Vec<u8> {
+    let rng = &mut thread_rng();
+    let chosen: Vec<&String> = (0..length)
+        .map(|_| words.choose(rng).expect("wordlist must not be empty"))
+        .collect();
+
+    chosen.iter()
+        .map(|word| word.as_str())
+        .collect::<Vec<&str>>()
+        .join(separator)
+        .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::from_utf8;
+
+    use super::{generate_passphrase, load_words};
+
+    #[test]
+    fn loads_default_wordlist_without_a_path() {
+        let words = load_words(None, "\n").unwrap();
+
+        assert!(!words.is_empty());
+    }
+
+    #[test]
+    fn generates_passphrase_of_expected_word_count() {
+        let words = load_words(None, "\n").unwrap();
+        let bytes = generate_passphrase(5, " ", &words);
+        let string = from_utf8(&bytes).unwrap();
+
+        assert_eq!(string.split(' ').count(), 5);
+    }
+}
@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use rand::{thread_rng, Rng};
+use rand::seq::SliceRandom;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+const AMBIGUOUS: &str = "lI1O0|";
+
+#[derive(Debug)]
+pub enum PasswordError {
+    TooManyRequiredClasses { required: usize, length: usize },
+    RequiredClassDisabled(&'static str)
+}
+
+impl Display for PasswordError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordError::TooManyRequiredClasses { required, length } =>
+                write!(formatter, "{required} character classes are required, but the password is only {length} characters long"),
+            PasswordError::RequiredClassDisabled(class) =>
+                write!(formatter, "{class} were required, but also disabled")
+        }
+    }
+}
+
+impl Error for PasswordError {}
+
+/// The character classes that may be enabled, excluded, or made mandatory in a generated password.
+#[derive(Default)]
+pub struct PasswordOptions {
+    pub numbers: bool,
+    pub symbols: bool,
+    pub require_digits: bool,
+    pub require_symbols: bool,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub no_ambiguous: bool
+}
+
+fn strip_ambiguous(charset: &str) -> String {
+    charset.chars()
+        .filter(|character| !AMBIGUOUS.contains(*character))
+        .collect()
+}
+
+/// Generate a random password, optionally guaranteeing at least one character from each
+/// required class and excluding visually confusable characters.
+///
+/// A mandatory character is placed for each required class first, the remainder of `length`
+/// is filled from the combined pool, and the whole buffer is then shuffled so mandatory
+/// characters are not stuck at fixed positions.
+pub fn generate_password(length: usize, options: &PasswordOptions) -> Result<Vec<u8>, PasswordError> {
+    if options.require_digits && !options.numbers { return Err(PasswordError::RequiredClassDisabled("digits")); }
+    if options.require_symbols && !options.symbols { return Err(PasswordError::RequiredClassDisabled("symbols")); }
+
+    let lowercase = if options.no_ambiguous { strip_ambiguous(LOWERCASE) } else { LOWERCASE.to_string() };
+    let uppercase = if options.no_ambiguous { strip_ambiguous(UPPERCASE) } else { UPPERCASE.to_string() };
+    let digits = if options.no_ambiguous { strip_ambiguous(DIGITS) } else { DIGITS.to_string() };
+    let symbols = if options.no_ambiguous { strip_ambiguous(SYMBOLS) } else { SYMBOLS.to_string() };
+
+    let mut required: Vec<&str> = Vec::new();
+
+    if options.require_lowercase { required.push(lowercase.as_str()); }
+    if options.require_uppercase { required.push(uppercase.as_str()); }
+    if options.require_digits { required.push(digits.as_str()); }
+    if options.require_symbols { required.push(symbols.as_str()); }
+
+    if required.len() > length {
+        return Err(PasswordError::TooManyRequiredClasses { required: required.len(), length });
+    }
+
+    let mut pool = lowercase.clone() + &uppercase;
+
+    if options.numbers { pool += &digits; }
+    if options.symbols { pool += &symbols; }
+
+    let pool: Vec<char> = pool.chars().collect();
+    let rng = &mut thread_rng();
+    let mut output: Vec<char> = Vec::with_capacity(length);
+
+    for charset in &required {
+        let characters: Vec<char> = charset.chars().collect();
+
+        output.push(*characters.choose(rng).unwrap());
+    }
+
+    for _ in output.len()..length {
+        output.push(pool[rng.gen_range(0..pool.len())]);
+    }
+
+    output.shuffle(rng);
+
+    Ok(output.iter().collect::<String>().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::from_utf8;
+
+    use super::{generate_password, PasswordError, PasswordOptions};
+
+    #[test]
+    fn generates_password_of_expected_length() {
+        let options = PasswordOptions { numbers: true, symbols: true, ..Default::default() };
+        let bytes = generate_password(32, &options).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert_eq!(string.chars().count(), 32);
+    }
+
+    #[test]
+    fn guarantees_required_classes_are_present() {
+        let options = PasswordOptions {
+            numbers: true,
+            symbols: true,
+            require_digits: true,
+            require_symbols: true,
+            require_uppercase: true,
+            require_lowercase: true,
+            ..Default::default()
+        };
+        let bytes = generate_password(8, &options).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert!(string.chars().any(|character| character.is_ascii_digit()));
+        assert!(string.chars().any(|character| "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~".contains(character)));
+        assert!(string.chars().any(|character| character.is_ascii_uppercase()));
+        assert!(string.chars().any(|character| character.is_ascii_lowercase()));
+    }
+
+    #[test]
+    fn excludes_ambiguous_characters() {
+        let options = PasswordOptions { numbers: true, symbols: true, no_ambiguous: true, ..Default::default() };
+        let bytes = generate_password(10000, &options).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert!(!string.chars().any(|character| "lI1O0|".contains(character)));
+    }
+
+    #[test]
+    fn rejects_too_many_required_classes() {
+        let options = PasswordOptions {
+            numbers: true,
+            symbols: true,
+            require_digits: true,
+            require_symbols: true,
+            require_uppercase: true,
+            require_lowercase: true,
+            ..Default::default()
+        };
+        let result = generate_password(2, &options);
+
+        assert!(matches!(result, Err(PasswordError::TooManyRequiredClasses { required: 4, length: 2 })));
+    }
+
+    #[test]
+    fn rejects_required_digits_when_digits_are_disabled() {
+        let options = PasswordOptions { numbers: false, require_digits: true, ..Default::default() };
+        let result = generate_password(8, &options);
+
+        assert!(matches!(result, Err(PasswordError::RequiredClassDisabled("digits"))));
+    }
+
+    #[test]
+    fn rejects_required_symbols_when_symbols_are_disabled() {
+        let options = PasswordOptions { symbols: false, require_symbols: true, ..Default::default() };
+        let result = generate_password(8, &options);
+
+        assert!(matches!(result, Err(PasswordError::RequiredClassDisabled("symbols"))));
+    }
+}
@@ -0,0 +1,203 @@
+use rand::{random, thread_rng, Rng};
+use rand::rngs::ThreadRng;
+use rand::distributions::{Distribution, Standard};
+use rand::seq::SliceRandom;
+
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+pub use super::passphrase::load_words as load_readable_words;
+
+enum WordCase {
+    Lower,
+    Upper,
+    Capitalized
+}
+
+impl Distribution<WordCase> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> WordCase {
+        match rng.gen_range(0..=2) {
+            0 => WordCase::Lower,
+            1 => WordCase::Upper,
+            _ => WordCase::Capitalized
+        }
+    }
+}
+
+fn apply_case(word: &str, case: WordCase) -> String {
+    match case {
+        WordCase::Lower => word.to_lowercase(),
+        WordCase::Upper => word.to_uppercase(),
+        WordCase::Capitalized => {
+            let mut characters = word.chars();
+
+            match characters.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + &characters.as_str().to_lowercase(),
+                None => String::new()
+            }
+        }
+    }
+}
+
+/// Select random words from `words` until their combined length reaches a target somewhere
+/// between `minimum_length` and `maximum_length`, leaving room for `reserved` characters worth
+/// of digits and symbols that will be inserted afterward.
+///
+/// Each word is drawn from those that keep the total within `maximum_length`. Selection stops
+/// early when no remaining word fits, so the total can still fall short of the target; the
+/// first word is always taken, even if it alone exceeds the budget.
+fn select_words(words: &[String], minimum_length: usize, maximum_length: usize, reserved: usize, rng: &mut ThreadRng) -> Vec<String> {
+    let target = if minimum_length >= maximum_length { minimum_length } else { rng.gen_range(minimum_length..=maximum_length) };
+    let budget = target.saturating_sub(reserved).max(1);
+    let ceiling = maximum_length.saturating_sub(reserved).max(budget);
+
+    let mut selected = Vec::new();
+    let mut total = 0;
+
+    loop {
+        let fitting: Vec<&String> = words.iter().filter(|word| total + word.len() <= ceiling).collect();
+
+        let word = match fitting.choose(rng) {
+            Some(word) => *word,
+            None if total == 0 => words.choose(rng).expect("wordlist must not be empty"),
+            None => break
+        };
+
+        total += word.len();
+        selected.push(word.clone());
+
+        if total >= budget { break; }
+    }
+
+    selected
+}
+
+/// Draw `digit_count` random digits followed by `symbol_count` random symbols, then shuffle
+/// their order so digits and symbols are interleaved.
+fn draw_insertions(digit_count: usize, symbol_count: usize, rng: &mut ThreadRng) -> Vec<char> {
+    let digits: Vec<char> = DIGITS.chars().collect();
+    let symbols: Vec<char> = SYMBOLS.chars().collect();
+
+    let mut insertions: Vec<char> = (0..digit_count)
+        .map(|_| digits[rng.gen_range(0..digits.len())])
+        .chain((0..symbol_count).map(|_| symbols[rng.gen_range(0..symbols.len())]))
+        .collect();
+
+    insertions.shuffle(rng);
+
+    insertions
+}
+
+/// Insert `insertions` at random boundaries between (and around) `words`, never inside a word.
+fn insert_between_words(words: &[String], insertions: &[char], rng: &mut ThreadRng) -> String {
+    let mut slots: Vec<Vec<char>> = vec![Vec::new(); words.len() + 1];
+
+    for character in insertions {
+        let slot = rng.gen_range(0..slots.len());
+
+        slots[slot].push(*character);
+    }
+
+    let mut output = String::new();
+
+    for (index, word) in words.iter().enumerate() {
+        output.extend(&slots[index]);
+        output.push_str(word);
+    }
+
+    output.extend(slots.last().unwrap());
+
+    output
+}
+
+/// Insert `insertions` at uniformly random character positions anywhere in the joined words.
+fn insert_anywhere(words: &[String], insertions: &[char], rng: &mut ThreadRng) -> String {
+    let mut characters: Vec<char> = words.concat().chars().collect();
+
+    for character in insertions {
+        let position = rng.gen_range(0..=characters.len());
+
+        characters.insert(position, *character);
+    }
+
+    characters.into_iter().collect()
+}
+
+/// Generate a memorable password by selecting real words from a wordlist, optionally
+/// randomizing the case of each word, and inserting a configurable number of digits and symbols
+/// either strictly between words or anywhere in the resulting string.
+pub fn generate_readable_password(
+    words: &[String],
+    minimum_length: usize,
+    maximum_length: usize,
+    digit_count: usize,
+    symbol_count: usize,
+    between_words: bool,
+    randomize_case: bool
+) -> Vec<u8> {
+    let rng = &mut thread_rng();
+    let mut selected = select_words(words, minimum_length, maximum_length, digit_count + symbol_count, rng);
+
+    if randomize_case {
+        selected = selected.iter()
+            .map(|word| apply_case(word, random()))
+            .collect();
+    }
+
+    let insertions = draw_insertions(digit_count, symbol_count, rng);
+
+    let output = if between_words {
+        insert_between_words(&selected, &insertions, rng)
+    } else {
+        insert_anywhere(&selected, &insertions, rng)
+    };
+
+    output.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::from_utf8;
+
+    use super::{generate_readable_password, load_readable_words};
+
+    #[test]
+    fn places_requested_digit_and_symbol_counts() {
+        let words = load_readable_words(None, "\n").unwrap();
+        let bytes = generate_readable_password(&words, 12, 20, 3, 2, true, true);
+        let string = from_utf8(&bytes).unwrap();
+
+        assert_eq!(string.chars().filter(|character| character.is_ascii_digit()).count(), 3);
+        assert_eq!(string.chars().filter(|character| "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~".contains(*character)).count(), 2);
+    }
+
+    #[test]
+    fn keeps_digits_and_symbols_between_words() {
+        let words = vec!["alpha".to_string(), "bravo".to_string()];
+        let bytes = generate_readable_password(&words, 4, 30, 2, 2, true, false);
+        let string = from_utf8(&bytes).unwrap();
+
+        // Every run of letters must be made only of whole words, i.e. nothing was inserted inside one.
+        for segment in string.split(|character: char| !character.is_ascii_alphabetic()).filter(|segment| !segment.is_empty()) {
+            assert_eq!(segment.len() % 5, 0, "'{segment}' is not made of whole words");
+            assert!(segment.as_bytes().chunks(5).all(|chunk| chunk == b"alpha" || chunk == b"bravo"), "'{segment}' splits a word");
+        }
+    }
+
+    #[test]
+    fn respects_maximum_length_budget() {
+        let words = load_readable_words(None, "\n").unwrap();
+        let longest_word_length = words.iter().map(|word| word.len()).max().unwrap();
+        let maximum_length = 8;
+        let digit_count = 1;
+        let symbol_count = 1;
+
+        let bytes = generate_readable_password(&words, 4, maximum_length, digit_count, symbol_count, false, true);
+
+        // At least one word is always included even if it alone exceeds the length budget, so
+        // the true worst case is the longest word in the list plus the requested insertions.
+        let bound = maximum_length.max(longest_word_length + digit_count + symbol_count);
+
+        assert!(bytes.len() <= bound, "expected at most {bound} bytes, got {}", bytes.len());
+    }
+}
@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use rand::{thread_rng, Rng};
+use rand::rngs::ThreadRng;
+use regex_syntax::Parser;
+use regex_syntax::hir::{Class, Hir, HirKind, Literal};
+
+const DEFAULT_MAX_REPEAT: u32 = 32;
+
+#[derive(Debug)]
+pub enum RegexError {
+    Parse(regex_syntax::Error),
+    EmptyLanguage
+}
+
+impl Display for RegexError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RegexError::Parse(error) => write!(formatter, "failed to parse pattern: {error}"),
+            RegexError::EmptyLanguage => write!(formatter, "pattern matches no strings")
+        }
+    }
+}
+
+impl Error for RegexError {}
+
+impl From<regex_syntax::Error> for RegexError {
+    fn from(error: regex_syntax::Error) -> Self {
+        RegexError::Parse(error)
+    }
+}
+
+/// Pick a uniformly random codepoint from the union of ranges making up a character class.
+fn sample_class(class: &Class, rng: &mut ThreadRng) -> Result<char, RegexError> {
+    match class {
+        Class::Unicode(unicode) => {
+            let ranges = unicode.ranges();
+            let total: u32 = ranges.iter()
+                .map(|range| range.end() as u32 - range.start() as u32 + 1)
+                .sum();
+
+            if total == 0 { return Err(RegexError::EmptyLanguage); }
+
+            let mut offset = rng.gen_range(0..total);
+
+            for range in ranges {
+                let width = range.end() as u32 - range.start() as u32 + 1;
+
+                if offset < width {
+                    return char::from_u32(range.start() as u32 + offset).ok_or(RegexError::EmptyLanguage);
+                }
+
+                offset -= width;
+            }
+
+            Err(RegexError::EmptyLanguage)
+        }
+        Class::Bytes(bytes) => {
+            let ranges = bytes.ranges();
+            let total: u32 = ranges.iter()
+                .map(|range| range.end() as u32 - range.start() as u32 + 1)
+                .sum();
+
+            if total == 0 { return Err(RegexError::EmptyLanguage); }
+
+            let mut offset = rng.gen_range(0..total);
+
+            for range in ranges {
+                let width = range.end() as u32 - range.start() as u32 + 1;
+
+                if offset < width {
+                    return Ok((range.start() as u32 + offset) as u8 as char);
+                }
+
+                offset -= width;
+            }
+
+            Err(RegexError::EmptyLanguage)
+        }
+    }
+}
+
+/// Recursively sample a string matching the given HIR node.
+///
+/// Literals are emitted verbatim, character classes draw a uniform codepoint, concatenations
+/// sample each child in order, alternations pick one branch uniformly, and repetitions choose a
+/// count uniformly within `{min,max}`, capping unbounded repeats at `max_repeat`. Anchors and
+/// word boundaries are zero-width no-ops.
+fn sample_hir(hir: &Hir, max_repeat: u32, rng: &mut ThreadRng, output: &mut Vec<u8>) -> Result<(), RegexError> {
+    match hir.kind() {
+        HirKind::Empty => Ok(()),
+        HirKind::Literal(Literal(bytes)) => {
+            output.extend_from_slice(bytes);
+
+            Ok(())
+        }
+        HirKind::Class(class) => {
+            let character = sample_class(class, rng)?;
+            let mut buffer = [0u8; 4];
+
+            output.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+
+            Ok(())
+        }
+        HirKind::Look(_) => Ok(()),
+        HirKind::Capture(capture) => sample_hir(capture.sub.as_ref(), max_repeat, rng, output),
+        HirKind::Concat(children) => {
+            for child in children { sample_hir(child, max_repeat, rng, output)?; }
+
+            Ok(())
+        }
+        HirKind::Alternation(branches) => {
+            if branches.is_empty() { return Err(RegexError::EmptyLanguage); }
+
+            let index = rng.gen_range(0..branches.len());
+
+            sample_hir(&branches[index], max_repeat, rng, output)
+        }
+        HirKind::Repetition(repetition) => {
+            let effective_max = repetition.max.unwrap_or(max_repeat).max(repetition.min);
+            let count = if effective_max == repetition.min { repetition.min } else { rng.gen_range(repetition.min..=effective_max) };
+
+            for _ in 0..count { sample_hir(repetition.sub.as_ref(), max_repeat, rng, output)?; }
+
+            Ok(())
+        }
+    }
+}
+
+/// Generate a random string matching a regular expression pattern.
+///
+/// `max_repeat` bounds how many times an unbounded repetition (`*`, `+`, `{n,}`) may repeat.
+pub fn generate_regex(pattern: &str, max_repeat: Option<u32>) -> Result<Vec<u8>, RegexError> {
+    let hir = Parser::new().parse(pattern)?;
+    let rng = &mut thread_rng();
+    let mut output = Vec::new();
+
+    sample_hir(&hir, max_repeat.unwrap_or(DEFAULT_MAX_REPEAT), rng, &mut output)?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::from_utf8;
+
+    use super::generate_regex;
+
+    #[test]
+    fn generates_literal_pattern() {
+        let bytes = generate_regex("hello", None).unwrap();
+
+        assert_eq!(bytes, b"hello");
+    }
+
+    #[test]
+    fn generates_digits_within_class() {
+        let bytes = generate_regex("[0-9]{4}", None).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert_eq!(string.chars().count(), 4);
+        assert!(string.chars().all(|character| character.is_ascii_digit()));
+    }
+
+    #[test]
+    fn generates_within_alternation() {
+        let bytes = generate_regex("cat|dog", None).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert!(string == "cat" || string == "dog");
+    }
+
+    #[test]
+    fn caps_unbounded_repetition_at_max_repeat() {
+        let bytes = generate_regex("a*", Some(8)).unwrap();
+        let string = from_utf8(&bytes).unwrap();
+
+        assert!(string.chars().count() <= 8);
+    }
+
+    #[test]
+    fn rejects_empty_language() {
+        let result = generate_regex("[^\\x00-\\x{10FFFF}]", None);
+
+        assert!(result.is_err());
+    }
+}
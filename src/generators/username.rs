@@ -1,3 +1,9 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+use std::io;
+use std::fs;
+
 use rand::{random, thread_rng, Rng};
 use rand::rngs::ThreadRng;
 use rand::distributions::{Distribution, Standard};
@@ -127,11 +133,185 @@ pub fn generate_complex_username(capitalize: bool, length: usize) -> Vec<u8> {
         .into_bytes()
 }
 
+/// The embedded default syllable sets, one of which is chosen at random when the caller does not
+/// supply a syllable file.
+const DEFAULT_THEMES: [&str; 2] = [
+    // A vaguely fantasy-sounding theme.
+    "\
+al,2
+el,2
+or,2
+ar,1.5
+-bran,1
+-kal,1
+-thor,1
+-mor,1
+dor+,1
+wyn+,1
+ric+,1
+gard+,1",
+    // A vaguely techno-sounding theme.
+    "\
+ex,2
+yn,2
+zo,2
+vi,1.5
+-cy,1
+-nex,1
+-sol,1
+-pix,1
+tron+,1
+byte+,1
+core+,1
+flux+,1"
+];
+
+/// One syllable and the relative weight with which it should be drawn.
+struct Syllable {
+    text: String,
+    weight: f64
+}
+
+/// Syllables bucketed by where in a themed username they may appear.
+#[derive(Default)]
+struct ThemedSyllables {
+    prefixes: Vec<Syllable>,
+    centers: Vec<Syllable>,
+    suffixes: Vec<Syllable>
+}
+
+#[derive(Debug)]
+pub enum ThemedError {
+    Io(io::Error),
+    NonPositiveWeight { syllable: String, weight: f64 }
+}
+
+impl Display for ThemedError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemedError::Io(error) => write!(formatter, "{error}"),
+            ThemedError::NonPositiveWeight { syllable, weight } =>
+                write!(formatter, "syllable '{syllable}' has a non-positive weight ({weight}); weights must be greater than zero")
+        }
+    }
+}
+
+impl Error for ThemedError {}
+
+impl From<io::Error> for ThemedError {
+    fn from(error: io::Error) -> Self {
+        ThemedError::Io(error)
+    }
+}
+
+/// Parse a syllable file into its prefix, center, and suffix buckets.
+///
+/// Each non-empty line is one syllable. A leading `-` marks the syllable as prefix-only, a
+/// trailing `+` marks it as suffix-only, and otherwise it is treated as a center syllable. An
+/// optional trailing `,weight` sets its relative selection weight, which defaults to `1.0` and
+/// must be greater than zero.
+fn parse_themed_syllables(content: &str) -> Result<ThemedSyllables, ThemedError> {
+    let mut syllables = ThemedSyllables::default();
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.is_empty() { continue; }
+
+        let (body, weight) = match line.rsplit_once(',') {
+            Some((body, raw_weight)) => match raw_weight.trim().parse::<f64>() {
+                Ok(weight) => (body, weight),
+                Err(_) => (line, 1.0)
+            },
+            None => (line, 1.0)
+        };
+
+        if !(weight > 0.0) {
+            return Err(ThemedError::NonPositiveWeight { syllable: body.to_string(), weight });
+        }
+
+        let syllable = if let Some(text) = body.strip_prefix('-') {
+            syllables.prefixes.push(Syllable { text: text.to_string(), weight });
+            continue;
+        } else if let Some(text) = body.strip_suffix('+') {
+            syllables.suffixes.push(Syllable { text: text.to_string(), weight });
+            continue;
+        } else {
+            Syllable { text: body.to_string(), weight }
+        };
+
+        syllables.centers.push(syllable);
+    }
+
+    Ok(syllables)
+}
+
+/// Draw one syllable from a bucket using a cumulative-weight random selection.
+///
+/// Assumes every syllable in `bucket` carries a positive weight, which `parse_themed_syllables`
+/// guarantees.
+fn choose_weighted<'a>(bucket: &'a [Syllable], rng: &mut ThreadRng) -> &'a Syllable {
+    let total: f64 = bucket.iter().map(|syllable| syllable.weight).sum();
+    let mut draw = rng.gen_range(0.0..total);
+
+    for syllable in bucket {
+        if draw < syllable.weight { return syllable; }
+
+        draw -= syllable.weight;
+    }
+
+    bucket.last().unwrap()
+}
+
+/// Generate a pronounceable username from a classified, weighted syllable set.
+///
+/// A name is built as `prefix + (min..=max centers) + suffix`, drawing each slot from its own
+/// bucket with a weighted random selection so that distinctive syllables appear more or less
+/// often as configured, rather than with uniform probability.
+pub fn generate_themed_username(capitalize: bool, minimum_centers: usize, maximum_centers: usize, syllables: &ThemedSyllables) -> Vec<u8> {
+    if syllables.prefixes.is_empty() || syllables.suffixes.is_empty() { return Vec::new(); }
+
+    let rng = &mut thread_rng();
+    let mut output = String::new();
+
+    output.push_str(&choose_weighted(&syllables.prefixes, rng).text);
+
+    if !syllables.centers.is_empty() {
+        let center_count = if minimum_centers >= maximum_centers { minimum_centers } else { rng.gen_range(minimum_centers..=maximum_centers) };
+
+        for _ in 0..center_count {
+            output.push_str(&choose_weighted(&syllables.centers, rng).text);
+        }
+    }
+
+    output.push_str(&choose_weighted(&syllables.suffixes, rng).text);
+
+    let mut characters: Vec<char> = output.chars().collect();
+
+    if capitalize && !characters.is_empty() { characters[0].make_ascii_uppercase(); }
+
+    characters.iter().collect::<String>().into_bytes()
+}
+
+/// Load a syllable set from a file on disk, or fall back to a randomly chosen embedded default.
+pub fn load_themed_syllables(path: Option<&Path>) -> Result<ThemedSyllables, ThemedError> {
+    let content = match path {
+        Some(path) => fs::read_to_string(path)?,
+        None => {
+            let theme = DEFAULT_THEMES.choose(&mut thread_rng()).unwrap();
+
+            theme.to_string()
+        }
+    };
+
+    parse_themed_syllables(&content)
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::from_utf8;
 
-    use super::{generate_simple_username, generate_complex_username};
+    use super::{generate_simple_username, generate_complex_username, generate_themed_username, parse_themed_syllables, ThemedError, DEFAULT_THEMES};
 
     #[test]
     fn generates_ten_thousand_character_simple_username() {
@@ -164,4 +344,47 @@ mod tests {
 
         assert_eq!(bytes.len(), 0)
     }
+
+    #[test]
+    fn bucketizes_syllables_by_classification() {
+        let syllables = parse_themed_syllables("-al\nli\nen+\n-bo,2\nmi,0.5\nto+,3").unwrap();
+
+        assert_eq!(syllables.prefixes.len(), 2);
+        assert_eq!(syllables.centers.len(), 2);
+        assert_eq!(syllables.suffixes.len(), 2);
+    }
+
+    #[test]
+    fn generates_themed_username_from_each_default_theme() {
+        for theme in DEFAULT_THEMES {
+            let syllables = parse_themed_syllables(theme).unwrap();
+            let bytes = generate_themed_username(true, 1, 3, &syllables);
+            let string = from_utf8(&bytes).unwrap();
+
+            assert!(!string.is_empty());
+            assert!(string.chars().next().unwrap().is_uppercase());
+        }
+    }
+
+    #[test]
+    fn themed_username_is_empty_without_prefix_or_suffix_syllables() {
+        let syllables = parse_themed_syllables("li\nmi").unwrap();
+        let bytes = generate_themed_username(false, 1, 2, &syllables);
+
+        assert_eq!(bytes.len(), 0);
+    }
+
+    #[test]
+    fn rejects_zero_weight_syllable() {
+        let result = parse_themed_syllables("-al,0");
+
+        assert!(matches!(result, Err(ThemedError::NonPositiveWeight { weight, .. }) if weight == 0.0));
+    }
+
+    #[test]
+    fn rejects_negative_weight_syllable() {
+        let result = parse_themed_syllables("-al,-1");
+
+        assert!(matches!(result, Err(ThemedError::NonPositiveWeight { weight, .. }) if weight == -1.0));
+    }
 }